@@ -0,0 +1,92 @@
+// Typed conversions for turning plain string records (CSV rows, key=value
+// config lines, ...) into `Account` values. Each column declares how its
+// text should be parsed via a `Conversion`, the same FromStr-dispatch idea
+// used to import data from external files.
+use crate::Account;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Conversion {
+    Integer,
+    Float,
+    String,
+    // Parses a decimal string like "1000.00" into integer minor units
+    // (cents), matching the integer-balance representation Account uses.
+    Currency,
+}
+
+// The parsed form of a single column, before it's assembled into an Account.
+enum ConvertedValue {
+    Int(i64),
+    Float(f64),
+    Str(String),
+    Currency(u64),
+}
+
+impl Conversion {
+    fn apply(&self, raw: &str) -> Result<ConvertedValue, &'static str> {
+        match self {
+            Conversion::Integer => raw.parse::<i64>().map(ConvertedValue::Int).map_err(|_| "expected integer"),
+            Conversion::Float => raw.parse::<f64>().map(ConvertedValue::Float).map_err(|_| "expected float"),
+            Conversion::String => Ok(ConvertedValue::Str(raw.to_string())),
+            Conversion::Currency => {
+                let dollars: f64 = raw.parse().map_err(|_| "expected currency")?;
+                if !dollars.is_finite() || dollars < 0.0 {
+                    return Err("expected currency");
+                }
+                Ok(ConvertedValue::Currency((dollars * 100.0).round() as u64))
+            }
+        }
+    }
+}
+
+impl Account {
+    // Parse one record - columns in order (id, name, balance, authority) -
+    // into an Account, applying `spec[i]` to `fields[i]` and surfacing
+    // exactly which column failed and why.
+    pub fn from_record(fields: &[&str], spec: &[Conversion]) -> Result<Account, String> {
+        if fields.len() != spec.len() {
+            return Err(format!(
+                "expected {} columns, got {}",
+                spec.len(),
+                fields.len()
+            ));
+        }
+        if fields.len() != 4 {
+            return Err("expected 4 columns: id, name, balance, authority".to_string());
+        }
+
+        let mut values = Vec::with_capacity(fields.len());
+        for (i, (raw, conversion)) in fields.iter().zip(spec.iter()).enumerate() {
+            let value = conversion.apply(raw).map_err(|msg| format!("column {}: {}", i, msg))?;
+            values.push(value);
+        }
+
+        let id = match &values[0] {
+            ConvertedValue::Int(n) => u32::try_from(*n).map_err(|_| "column 0: id must not be negative".to_string())?,
+            _ => return Err("column 0: expected integer".to_string()),
+        };
+        let name = match &values[1] {
+            ConvertedValue::Str(s) => s.clone(),
+            _ => return Err("column 1: expected string".to_string()),
+        };
+        let balance = match &values[2] {
+            ConvertedValue::Currency(cents) => *cents,
+            ConvertedValue::Int(n) => u64::try_from(*n).map_err(|_| "column 2: balance must not be negative".to_string())?,
+            ConvertedValue::Float(dollars) => {
+                if !dollars.is_finite() || *dollars < 0.0 {
+                    return Err("column 2: balance must not be negative".to_string());
+                }
+                (*dollars * 100.0).round() as u64
+            }
+            _ => return Err("column 2: expected integer, float, or currency".to_string()),
+        };
+        let authority = match &values[3] {
+            ConvertedValue::Int(n) => {
+                u64::try_from(*n).map_err(|_| "column 3: authority must not be negative".to_string())?
+            }
+            _ => return Err("column 3: expected integer".to_string()),
+        };
+
+        Ok(Account::new(id, name, balance, authority))
+    }
+}
@@ -0,0 +1,124 @@
+// Append-only transaction log for `Bank`, modeled on Solana's AppendVec
+// (a flat byte buffer you only ever grow) and Anchor's leading type
+// discriminator (a tag byte that says how to parse what follows).
+use crate::Account;
+
+// One entry per account-changing event `Bank` performs. Each variant
+// serializes to a one-byte discriminator followed by its fields.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JournalEntry {
+    AccountOpened { id: u32, name: String, balance: u64, authority: u64 },
+    BalanceChanged { id: u32, balance: u64 },
+    AccountClosed { id: u32 },
+}
+
+const TAG_ACCOUNT_OPENED: u8 = 0;
+const TAG_BALANCE_CHANGED: u8 = 1;
+const TAG_ACCOUNT_CLOSED: u8 = 2;
+
+impl JournalEntry {
+    pub fn account_opened(acc: &Account) -> Self {
+        JournalEntry::AccountOpened {
+            id: acc.id,
+            name: acc.name.clone(),
+            balance: acc.balance,
+            authority: acc.authority,
+        }
+    }
+
+    // Append this entry's bytes onto a growable log buffer.
+    pub fn encode(&self, out: &mut Vec<u8>) {
+        match self {
+            JournalEntry::AccountOpened { id, name, balance, authority } => {
+                out.push(TAG_ACCOUNT_OPENED);
+                out.extend_from_slice(&id.to_le_bytes());
+                let name_bytes = name.as_bytes();
+                out.extend_from_slice(&(name_bytes.len() as u32).to_le_bytes());
+                out.extend_from_slice(name_bytes);
+                out.extend_from_slice(&balance.to_le_bytes());
+                out.extend_from_slice(&authority.to_le_bytes());
+            }
+            JournalEntry::BalanceChanged { id, balance } => {
+                out.push(TAG_BALANCE_CHANGED);
+                out.extend_from_slice(&id.to_le_bytes());
+                out.extend_from_slice(&balance.to_le_bytes());
+            }
+            JournalEntry::AccountClosed { id } => {
+                out.push(TAG_ACCOUNT_CLOSED);
+                out.extend_from_slice(&id.to_le_bytes());
+            }
+        }
+    }
+
+    // Decode one entry starting at `bytes[0]`, returning the entry and how
+    // many bytes it consumed so the caller can advance to the next one.
+    // An unrecognized discriminator is rejected rather than guessed at.
+    pub fn decode(bytes: &[u8]) -> Result<(JournalEntry, usize), String> {
+        let &tag = bytes.first().ok_or("journal entry missing discriminator")?;
+        let mut cursor = Cursor { bytes, pos: 1 };
+
+        let entry = match tag {
+            TAG_ACCOUNT_OPENED => {
+                let id = cursor.read_u32()?;
+                let name_len = cursor.read_u32()? as usize;
+                let name = cursor.read_string(name_len)?;
+                let balance = cursor.read_u64()?;
+                let authority = cursor.read_u64()?;
+                JournalEntry::AccountOpened { id, name, balance, authority }
+            }
+            TAG_BALANCE_CHANGED => {
+                let id = cursor.read_u32()?;
+                let balance = cursor.read_u64()?;
+                JournalEntry::BalanceChanged { id, balance }
+            }
+            TAG_ACCOUNT_CLOSED => {
+                let id = cursor.read_u32()?;
+                JournalEntry::AccountClosed { id }
+            }
+            other => return Err(format!("unknown journal entry discriminator {}", other)),
+        };
+
+        Ok((entry, cursor.pos))
+    }
+}
+
+// Tiny read cursor so decode() doesn't have to thread an offset through
+// every field read by hand.
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn read_u32(&mut self) -> Result<u32, String> {
+        let chunk: [u8; 4] = self
+            .bytes
+            .get(self.pos..self.pos + 4)
+            .ok_or("journal entry truncated reading u32")?
+            .try_into()
+            .unwrap();
+        self.pos += 4;
+        Ok(u32::from_le_bytes(chunk))
+    }
+
+    fn read_u64(&mut self) -> Result<u64, String> {
+        let chunk: [u8; 8] = self
+            .bytes
+            .get(self.pos..self.pos + 8)
+            .ok_or("journal entry truncated reading u64")?
+            .try_into()
+            .unwrap();
+        self.pos += 8;
+        Ok(u64::from_le_bytes(chunk))
+    }
+
+    fn read_string(&mut self, len: usize) -> Result<String, String> {
+        let slice = self
+            .bytes
+            .get(self.pos..self.pos + len)
+            .ok_or("journal entry truncated reading name")?;
+        let s = String::from_utf8(slice.to_vec()).map_err(|e| e.to_string())?;
+        self.pos += len;
+        Ok(s)
+    }
+}
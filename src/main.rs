@@ -1,5 +1,23 @@
-// Import HashMap from standard library - similar to Python's dict
+// HashMap backs each checkpoint overlay (see the fork stack on Bank).
 use std::collections::HashMap;
+// HashSet tracks the account IDs currently "in flight" inside a transfer,
+// the same role Solana's runtime gives its account-lock set.
+use std::collections::HashSet;
+
+// Storage backends live in their own module now that there's more than
+// one of them (see store.rs).
+mod store;
+pub use store::{AccountStore, FileStore, MemoryStore};
+
+// Append-only log of account events, kept in memory and replayable (see
+// journal.rs).
+mod journal;
+pub use journal::JournalEntry;
+
+// Field-conversion layer for importing accounts from string records (see
+// convert.rs).
+mod convert;
+pub use convert::Conversion;
 
 // #[derive(Debug, Clone)] - Auto-generates traits:
 // Debug: allows printing with {:?} (like Python's __repr__)
@@ -11,8 +29,14 @@ pub struct Account {
     pub id: u32,
     // String = owned, growable string (like Python str but with ownership)
     pub name: String,
-    // f64 = 64-bit floating point number (like Python float)
-    pub balance: f64,
+    // Balance in minor units (e.g. cents), not a float - u64 can't go
+    // negative and has none of f64's rounding error, so every cent is
+    // accounted for exactly.
+    pub balance: u64,
+    // Owner key: the only signer allowed to authorize a mutation on this
+    // account, mirroring Anchor's `authority` field and Solana's
+    // `AccountInfo { is_signer }` check.
+    pub authority: u64,
 }
 
 // impl block contains methods for Account struct
@@ -21,36 +45,283 @@ impl Account {
     // Associated function (constructor) - called with Account::new()
     // pub makes it publicly accessible
     // Self refers to Account type (cleaner than writing Account)
-    pub fn new(id: u32, name: String, balance: f64) -> Self {
+    pub fn new(id: u32, name: String, balance: u64, authority: u64) -> Self {
         // Struct initialization - field names match parameter names
-        Account { id, name, balance }
+        Account { id, name, balance, authority }
+    }
+
+    // Add to the balance, rejecting the deposit instead of silently
+    // wrapping if it would overflow u64 - borrowed from Substrate's
+    // Saturating/checked-amount style of guarding every arithmetic op.
+    pub fn deposit(&mut self, amount: u64) -> Result<u64, String> {
+        self.balance = self
+            .balance
+            .checked_add(amount)
+            .ok_or_else(|| "deposit would overflow balance".to_string())?;
+        Ok(self.balance)
+    }
+
+    // Subtract from the balance, rejecting the withdrawal instead of
+    // underflowing (which on an unsigned integer would wrap to a huge
+    // number rather than go negative).
+    pub fn withdraw(&mut self, amount: u64) -> Result<u64, String> {
+        self.balance = self
+            .balance
+            .checked_sub(amount)
+            .ok_or_else(|| "insufficient funds".to_string())?;
+        Ok(self.balance)
     }
+
+    // Like deposit, but clamps at u64::MAX instead of failing - for
+    // callers that would rather cap a balance than reject the deposit.
+    pub fn saturating_deposit(&mut self, amount: u64) -> u64 {
+        self.balance = self.balance.saturating_add(amount);
+        self.balance
+    }
+}
+
+// Proof that a particular key authorized this call, handed to the methods
+// that mutate an account - analogous to Solana's `AccountInfo { is_signer }`.
+#[derive(Debug, Clone, Copy)]
+pub struct Signer(pub u64);
+
+// The set of keys that have signed off on the current operation. Callers
+// build one, add every signer that approved the transaction, and pass it
+// by reference to whichever Bank method needs authorization.
+#[derive(Debug, Default)]
+pub struct TxContext {
+    pub signers: HashSet<u64>,
 }
 
-// Bank struct - only derives Debug (can't clone because HashMap is complex)
-#[derive(Debug)]
-pub struct Bank {
-    // Private field (no pub) - encapsulation like Python's _accounts
-    // HashMap<K, V> = key-value store like Python dict
-    // u32 = account ID (key), Account = account data (value)
-    accounts: HashMap<u32, Account>,
+impl TxContext {
+    pub fn new() -> Self {
+        TxContext { signers: HashSet::new() }
+    }
+
+    // Record that `signer` approved this transaction.
+    pub fn sign(&mut self, signer: Signer) -> &mut Self {
+        self.signers.insert(signer.0);
+        self
+    }
+}
+
+// Bank is generic over its storage backend - `S: AccountStore` - instead
+// of hard-coding a HashMap, so the same logic works whether accounts live
+// in memory or on disk.
+pub struct Bank<S: AccountStore> {
+    // The backend accounts actually live in. Reads go through `load()`,
+    // writes through `update()`/`remove()` - see store.rs.
+    store: S,
     // Tracks next available ID for auto-assignment
     next_id: u32,
+    // Account IDs currently held by an in-progress transfer. Populated at
+    // the start of transfer_batch and cleared once it finishes, so two
+    // transfers can never interleave writes to the same account.
+    locked: HashSet<u32>,
+    // Stack of checkpoint overlays, oldest (bottom) to newest (top). Each
+    // overlay holds only the accounts modified since its parent, the same
+    // copy-on-write-per-fork idea the validator uses for account forks.
+    // Reads walk from the top down to the base store; writes always land
+    // in the top overlay. `None` is a tombstone: the account was removed
+    // inside this fork, which a plain HashMap entry can't otherwise express.
+    forks: Vec<(u64, HashMap<u32, Option<Account>>)>,
+    // Monotonically increasing id handed out by checkpoint()
+    next_fork_id: u64,
+    // Append-only log of every account-opened/balance-changed/
+    // account-closed event, in order. Growable byte buffer a la Solana's
+    // AppendVec - `replay()` rebuilds a bank from nothing but these bytes.
+    journal: Vec<u8>,
 }
 
-// Implementation block for Bank methods
-impl Bank {
-    // Constructor function - creates new empty bank
-    // Self refers to Bank type
+// Bank::new() keeps the old in-memory behavior so existing callers don't
+// have to pick a backend.
+impl Bank<MemoryStore> {
     pub fn new() -> Self {
-        Bank {
-            // HashMap::new() creates empty hash map
-            accounts: HashMap::new(),
-            // Start IDs from 1 (0 used as "not set" indicator)
-            next_id: 1,
+        // A fresh MemoryStore can never fail to load, so this is safe to unwrap.
+        Self::with_store(MemoryStore::new()).expect("new in-memory store cannot fail to load")
+    }
+
+    // Rebuild a fresh in-memory Bank purely from a journal's bytes,
+    // applying each entry in order - crash recovery without ever touching
+    // a backend store.
+    pub fn replay(log: &[u8]) -> Result<Bank<MemoryStore>, String> {
+        let mut bank = Bank::new();
+        let mut offset = 0;
+        while offset < log.len() {
+            let (entry, consumed) = JournalEntry::decode(&log[offset..])?;
+            offset += consumed;
+            match entry {
+                JournalEntry::AccountOpened { id, name, balance, authority } => {
+                    bank.store.update(Account::new(id, name, balance, authority))?;
+                    if id >= bank.next_id {
+                        bank.next_id = id + 1;
+                    }
+                }
+                JournalEntry::BalanceChanged { id, balance } => {
+                    let mut acc = bank.find_account(id)?;
+                    acc.balance = balance;
+                    bank.store.update(acc)?;
+                }
+                JournalEntry::AccountClosed { id } => {
+                    bank.store.remove(id)?;
+                }
+            }
+        }
+        bank.journal = log.to_vec();
+        Ok(bank)
+    }
+}
+
+// Implementation block for Bank methods, generic over whichever backend
+// was plugged in.
+impl<S: AccountStore> Bank<S> {
+    // Wrap an existing backend in a Bank, picking up whatever accounts it
+    // already holds - this is what lets a FileStore-backed bank reload its
+    // state on startup instead of starting empty.
+    pub fn with_store(store: S) -> Result<Self, String> {
+        let next_id = store.load()?.iter().map(|acc| acc.id).max().map_or(1, |max| max + 1);
+        Ok(Bank {
+            store,
+            next_id,
+            locked: HashSet::new(),
+            forks: Vec::new(),
+            next_fork_id: 0,
+            journal: Vec::new(),
+        })
+    }
+
+    // Append one entry to the in-memory journal.
+    fn append_journal(&mut self, entry: JournalEntry) {
+        entry.encode(&mut self.journal);
+    }
+
+    // Bytes of every entry appended so far, suitable for persisting to
+    // disk and later handing to `Bank::replay`.
+    pub fn journal_bytes(&self) -> &[u8] {
+        &self.journal
+    }
+
+    // Look up a single account, checking checkpoint overlays from newest
+    // to oldest before falling back to the base store. The first overlay
+    // that mentions the id wins, including a tombstone recording that it
+    // was removed inside that fork.
+    fn find_account(&self, account_id: u32) -> Result<Account, String> {
+        for (_, overlay) in self.forks.iter().rev() {
+            if let Some(slot) = overlay.get(&account_id) {
+                return match slot {
+                    Some(acc) => Ok(acc.clone()),
+                    None => Err(format!("Account with ID {} not found", account_id)),
+                };
+            }
+        }
+        self.store
+            .load()?
+            .into_iter()
+            .find(|acc| acc.id == account_id)
+            .ok_or_else(|| format!("Account with ID {} not found", account_id))
+    }
+
+    // Write an account through to wherever writes currently belong: the
+    // top checkpoint overlay if one is open, or straight to the backend
+    // store otherwise.
+    fn write_account(&mut self, acc: Account) -> Result<(), String> {
+        if let Some((_, top)) = self.forks.last_mut() {
+            top.insert(acc.id, Some(acc));
+            Ok(())
+        } else {
+            self.store.update(acc)?;
+            Ok(())
         }
     }
 
+    // Remove an account through the same overlay-aware path as
+    // `write_account`: inside an open checkpoint this records a tombstone
+    // in the top overlay instead of touching the base store, so the
+    // removal is undone by a rollback just like a balance change would be.
+    fn remove_account_overlay(&mut self, account_id: u32) -> Result<Account, String> {
+        let account = self.find_account(account_id)?;
+        if let Some((_, top)) = self.forks.last_mut() {
+            top.insert(account_id, None);
+        } else {
+            self.store.remove(account_id)?;
+        }
+        Ok(account)
+    }
+
+    // Push a new, empty overlay onto the fork stack and return its id.
+    // Every write made after this call lands in the overlay instead of
+    // touching the base store, until the fork is committed or rolled back.
+    pub fn checkpoint(&mut self) -> u64 {
+        let id = self.next_fork_id;
+        self.next_fork_id += 1;
+        self.forks.push((id, HashMap::new()));
+        id
+    }
+
+    // Discard a checkpoint and every overlay pushed after it, restoring
+    // balances to what they were before the checkpoint was taken.
+    pub fn rollback(&mut self, fork_id: u64) -> Result<(), String> {
+        let pos = self
+            .forks
+            .iter()
+            .position(|(id, _)| *id == fork_id)
+            .ok_or_else(|| format!("no such checkpoint {}", fork_id))?;
+        self.forks.truncate(pos);
+        Ok(())
+    }
+
+    // Squash a checkpoint's overlay into its parent (the next fork down,
+    // or the base store if it was the bottom-most fork) and drop the fork.
+    // Only the top-most checkpoint can be committed, since squashing one
+    // from the middle of the stack would silently discard the forks above it.
+    pub fn commit(&mut self, fork_id: u64) -> Result<(), String> {
+        let pos = self
+            .forks
+            .iter()
+            .position(|(id, _)| *id == fork_id)
+            .ok_or_else(|| format!("no such checkpoint {}", fork_id))?;
+        if pos + 1 != self.forks.len() {
+            return Err("can only commit the top-most checkpoint".to_string());
+        }
+
+        let (_, overlay) = self.forks.pop().expect("position was just found in forks");
+        if let Some((_, parent)) = self.forks.last_mut() {
+            parent.extend(overlay);
+        } else {
+            // Squashing into the base store is the moment these writes
+            // become durable, so this is also the only place that journals
+            // them - journaling earlier, from whichever call made the
+            // change, would record entries a later rollback could still
+            // undo (see the rollback/replay divergence this was reported
+            // against).
+            let existed: HashSet<u32> = self.store.load()?.iter().map(|acc| acc.id).collect();
+            for (id, slot) in overlay {
+                match slot {
+                    Some(acc) => {
+                        let entry = if existed.contains(&id) {
+                            JournalEntry::BalanceChanged { id, balance: acc.balance }
+                        } else {
+                            JournalEntry::account_opened(&acc)
+                        };
+                        self.store.update(acc)?;
+                        self.append_journal(entry);
+                    }
+                    None => {
+                        // A tombstone for an account that was opened and
+                        // then removed within the same checkpoint never
+                        // reached the base store, so there's nothing to
+                        // remove or journal here.
+                        if existed.contains(&id) {
+                            self.store.remove(id)?;
+                            self.append_journal(JournalEntry::AccountClosed { id });
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
     // Add account to bank
     // &mut self = mutable borrow of Bank (can modify Bank's data)
     // mut account: Account = takes ownership of account AND makes it mutable
@@ -64,80 +335,197 @@ impl Bank {
             self.next_id += 1;
         }
 
-        // Check if account already exists using contains_key method
-        // &account.id = borrow the ID (don't move it)
-        if self.accounts.contains_key(&account.id) {
+        // Check if account already exists
+        if self.find_account(account.id).is_ok() {
             // Early return with error - format! creates String like Python f-string
             return Err(format!("Account with ID {} already exists", account.id));
         }
 
-        // Store account ID before moving account into HashMap
         let account_id = account.id;
-        // insert() moves account into HashMap - account can't be used after this
-        self.accounts.insert(account_id, account);
+        let opened = JournalEntry::account_opened(&account);
+        let journal_now = self.forks.is_empty();
+        self.write_account(account)?;
+        if journal_now {
+            self.append_journal(opened);
+        }
         // Return success with account ID
         Ok(account_id)
     }
 
+    // Bulk-load accounts from external records (e.g. rows read from a
+    // CSV file), converting each one with `Account::from_record` and
+    // auto-assigning an ID when a row's id column is "0".
+    pub fn import_records(&mut self, rows: &[Vec<String>], spec: &[Conversion]) -> Result<Vec<u32>, String> {
+        let mut ids = Vec::with_capacity(rows.len());
+        for (row_index, row) in rows.iter().enumerate() {
+            let fields: Vec<&str> = row.iter().map(|s| s.as_str()).collect();
+            let account = Account::from_record(&fields, spec).map_err(|e| format!("row {}: {}", row_index, e))?;
+            let id = self.add_account(account)?;
+            ids.push(id);
+        }
+        Ok(ids)
+    }
+
     // Remove account from bank
-    // &mut self = need mutable access to modify HashMap
+    // &mut self = need mutable access to modify the backend
     // account_id: u32 = copy the ID (u32 implements Copy trait)
     // Returns the removed account or error message
     pub fn remove_account(&mut self, account_id: u32) -> Result<Account, String> {
-        // Method chaining: remove() returns Option<Account>
-        // remove() takes ownership of the account (moves it out)
-        // ok_or_else() converts Option to Result
-        // || = closure (like Python lambda) that creates error message
-        self.accounts
-            .remove(&account_id)
-            .ok_or_else(|| format!("Account with ID {} not found", account_id))
+        let journal_now = self.forks.is_empty();
+        let account = self.remove_account_overlay(account_id)?;
+        if journal_now {
+            self.append_journal(JournalEntry::AccountClosed { id: account_id });
+        }
+        Ok(account)
     }
 
-    // Get account reference (read-only)
+    // Get account (read-only)
     // &self = immutable borrow (can't modify Bank)
-    // Returns Option<&Account> = either Some(reference) or None
-    // & = reference, not ownership - caller can read but not move account
-    pub fn get_account(&self, account_id: u32) -> Option<&Account> {
-        // get() returns Option<&V> - reference to value if exists
-        self.accounts.get(&account_id)
-    }
-
-    // Get mutable account reference
-    // &mut self = mutable borrow needed to get mutable reference to account
-    // Returns Option<&mut Account> = mutable reference if account exists
-    pub fn get_account_mut(&mut self, account_id: u32) -> Option<&mut Account> {
-        // get_mut() returns Option<&mut V> - mutable reference to value
-        self.accounts.get_mut(&account_id)
+    // Returns Option<Account> = either Some(owned copy) or None
+    pub fn get_account(&self, account_id: u32) -> Option<Account> {
+        self.find_account(account_id).ok()
     }
 
     // List all accounts
     // &self = immutable borrow (read-only access)
-    // Returns Vec<&Account> = vector of references to accounts
-    // Vec = dynamic array like Python list
-    pub fn list_accounts(&self) -> Vec<&Account> {
-        // values() returns iterator over HashMap values
-        // collect() consumes iterator and creates Vec
-        // Returns references, not owned accounts (accounts stay in HashMap)
-        self.accounts.values().collect()
+    // Returns Vec<Account> = every account currently in the backend
+    pub fn list_accounts(&self) -> Vec<Account> {
+        self.store.load().unwrap_or_default()
     }
 
-    // Update account balance
+    // Every mutating account operation goes through this: the account's
+    // authority key must be among ctx's signers, or the call is rejected.
+    fn require_authority(account: &Account, ctx: &TxContext) -> Result<(), String> {
+        if ctx.signers.contains(&account.authority) {
+            Ok(())
+        } else {
+            Err("unauthorized".to_string())
+        }
+    }
+
+    // Update account balance to an absolute value, routed through the
+    // checked deposit/withdraw arithmetic rather than assigning the field
+    // directly, so a balance can never silently overflow or underflow.
+    // Requires the account's authority to be a signer in `ctx`.
     // &mut self = mutable borrow to modify account data
     // Returns Result<(), String> = either Ok(()) for success or Err(message)
     // () = unit type (like Python's None for "no meaningful return value")
-    pub fn update_balance(&mut self, account_id: u32, new_balance: f64) -> Result<(), String> {
-        // Pattern matching on Option<&mut Account>
-        match self.get_account_mut(account_id) {
-            // Some(account) = found account, extract mutable reference
-            Some(account) => {
-                // Modify account through mutable reference
-                account.balance = new_balance;
-                // Return success with no data
-                Ok(())
+    pub fn update_balance(&mut self, account_id: u32, new_balance: u64, ctx: &TxContext) -> Result<(), String> {
+        let mut account = self.find_account(account_id)?;
+        Self::require_authority(&account, ctx)?;
+        if new_balance >= account.balance {
+            account.deposit(new_balance - account.balance)?;
+        } else {
+            account.withdraw(account.balance - new_balance)?;
+        }
+        let journal_now = self.forks.is_empty();
+        self.write_account(account)?;
+        if journal_now {
+            self.append_journal(JournalEntry::BalanceChanged { id: account_id, balance: new_balance });
+        }
+        Ok(())
+    }
+
+    // Withdraw funds from a single account, requiring its authority to
+    // have signed `ctx` - the Bank-level counterpart to Account::withdraw,
+    // which has no notion of who's asking.
+    pub fn withdraw(&mut self, account_id: u32, amount: u64, ctx: &TxContext) -> Result<u64, String> {
+        let mut account = self.find_account(account_id)?;
+        Self::require_authority(&account, ctx)?;
+        let new_balance = account.withdraw(amount)?;
+        let journal_now = self.forks.is_empty();
+        self.write_account(account)?;
+        if journal_now {
+            self.append_journal(JournalEntry::BalanceChanged { id: account_id, balance: new_balance });
+        }
+        Ok(new_balance)
+    }
+
+    // Move money from one account to another as a single all-or-nothing
+    // operation. Just a one-entry batch so all the locking and validation
+    // rules live in one place.
+    pub fn transfer(&mut self, from: u32, to: u32, amount: u64, ctx: &TxContext) -> Result<(), String> {
+        self.transfer_batch(&[(from, to, amount)], ctx)
+    }
+
+    // Apply a list of (from, to, amount) transfers atomically: either every
+    // leg succeeds or none of the balances change. Every debited account's
+    // authority must have signed `ctx` - crediting an account needs no
+    // authorization from its owner.
+    // &mut self = the whole bank is borrowed mutably for the duration
+    pub fn transfer_batch(&mut self, ops: &[(u32, u32, u64)], ctx: &TxContext) -> Result<(), String> {
+        // Collect every account id this batch touches. Solana's runtime
+        // rejects a transaction that locks the same account twice so two
+        // instructions can't race each other inside it; we apply the same
+        // rule across a batch of transfers.
+        let mut touched: HashSet<u32> = HashSet::new();
+        for &(from, to, _amount) in ops {
+            let from_dup = !touched.insert(from);
+            let to_dup = !touched.insert(to);
+            if from_dup || to_dup {
+                let dup = if from_dup { from } else { to };
+                return Err(format!("duplicate account {} in transfer batch", dup));
             }
-            // None = account not found
-            None => Err(format!("Account with ID {} not found", account_id)),
         }
+
+        // Every account referenced must actually exist before we lock or
+        // move anything.
+        for &id in &touched {
+            if self.find_account(id).is_err() {
+                return Err(format!("Account with ID {} not found", id));
+            }
+        }
+
+        // Refuse accounts already locked by another in-flight transfer.
+        if let Some(&id) = touched.iter().find(|id| self.locked.contains(id)) {
+            return Err(format!("Account with ID {} is locked by another transfer", id));
+        }
+        self.locked.extend(touched.iter().copied());
+
+        // Every account appears at most once in `touched` (duplicates were
+        // rejected above), so each leg can be checked independently
+        // without worrying about the same account being drained twice.
+        let outcome = (|| {
+            for &(from, to, amount) in ops {
+                let debit = self.find_account(from)?;
+                Self::require_authority(&debit, ctx)?;
+                if debit.balance < amount {
+                    return Err(format!("Account {} has insufficient funds", from));
+                }
+                if self.find_account(to)?.balance.checked_add(amount).is_none() {
+                    return Err(format!("deposit would overflow balance of account {}", to));
+                }
+            }
+
+            // Funds are sufficient for every leg, so it's now safe to apply
+            // all of them - a later leg failing can no longer leave an
+            // earlier leg half-applied.
+            let journal_now = self.forks.is_empty();
+            for &(from, to, amount) in ops {
+                let mut debit = self.find_account(from)?;
+                debit.withdraw(amount)?;
+                let debit_balance = debit.balance;
+                self.write_account(debit)?;
+                if journal_now {
+                    self.append_journal(JournalEntry::BalanceChanged { id: from, balance: debit_balance });
+                }
+
+                let mut credit = self.find_account(to)?;
+                credit.deposit(amount)?;
+                let credit_balance = credit.balance;
+                self.write_account(credit)?;
+                if journal_now {
+                    self.append_journal(JournalEntry::BalanceChanged { id: to, balance: credit_balance });
+                }
+            }
+            Ok(())
+        })();
+
+        // Clear the lock regardless of success or failure.
+        for id in &touched {
+            self.locked.remove(id);
+        }
+        outcome
     }
 }
 
@@ -148,8 +536,8 @@ fn main() {
     // Create accounts using constructor
     // Account::new() is associated function (like static method in Python)
     // to_string() converts &str to owned String
-    let account1 = Account::new(0, "Alice".to_string(), 1000.0);
-    let account2 = Account::new(0, "Bob".to_string(), 2000.0);
+    let account1 = Account::new(0, "Alice".to_string(), 100_000, 1001);
+    let account2 = Account::new(0, "Bob".to_string(), 200_000, 1002);
 
     // Add accounts - this MOVES accounts into bank (ownership transfer)
     // After this, account1 and account2 can't be used anymore
@@ -169,8 +557,7 @@ fn main() {
 
     // List all accounts
     println!("\nAll accounts:");
-    // bank.list_accounts() returns Vec<&Account>
-    // for loop borrows each reference - no ownership transfer
+    // bank.list_accounts() returns Vec<Account>, freshly loaded from the backend
     for account in bank.list_accounts() {
         // {:?} uses Debug trait to print struct contents
         println!("{:?}", account);
@@ -185,13 +572,20 @@ fn main() {
 
     // Update balance - different error handling pattern
     // if let Err(e) = only handles error case
-    if let Err(e) = bank.update_balance(1, 1500.0) {
+    let mut alice_ctx = TxContext::new();
+    alice_ctx.sign(Signer(1001));
+    if let Err(e) = bank.update_balance(1, 150_000, &alice_ctx) {
         println!("Error updating balance: {}", e);
     } else {
         // else handles Ok(()) case
         println!("Updated account 1 balance");
     }
 
+    // Without Alice's signature, the same call is rejected
+    if let Err(e) = bank.update_balance(1, 1, &TxContext::new()) {
+        println!("Unsigned update rejected: {}", e);
+    }
+
     // Remove account - returns the removed account on success
     match bank.remove_account(2) {
         // Ok(removed_account) = success, get ownership of removed account
@@ -204,4 +598,78 @@ fn main() {
     for account in bank.list_accounts() {
         println!("{:?}", account);
     }
+
+    // Demonstrate an atomic transfer between two fresh accounts
+    bank.add_account(Account::new(10, "Carol".to_string(), 50_000, 1003)).ok();
+    bank.add_account(Account::new(11, "Dave".to_string(), 0, 1004)).ok();
+    let mut carol_ctx = TxContext::new();
+    carol_ctx.sign(Signer(1003));
+    match bank.transfer(10, 11, 20_000, &carol_ctx) {
+        Ok(()) => println!("\nTransferred 20000 from Carol to Dave"),
+        Err(e) => println!("\nTransfer failed: {}", e),
+    }
+
+    // A batch with a duplicate account is rejected before anything moves
+    if let Err(e) = bank.transfer_batch(&[(10, 11, 5_000), (11, 10, 2_500)], &carol_ctx) {
+        println!("Batch rejected: {}", e);
+    }
+
+    // Checkpoint, try a balance change, then roll it back - the account
+    // ends up exactly where it started.
+    let fork = bank.checkpoint();
+    bank.update_balance(1, 999_900, &alice_ctx).ok();
+    println!("\nBalance inside checkpoint: {:?}", bank.get_account(1));
+    bank.rollback(fork).ok();
+    println!("Balance after rollback: {:?}", bank.get_account(1));
+
+    // This time keep the change by committing the checkpoint instead.
+    let fork = bank.checkpoint();
+    bank.update_balance(1, 424_200, &alice_ctx).ok();
+    bank.commit(fork).ok();
+    println!("Balance after commit: {:?}", bank.get_account(1));
+
+    // Replay the journal into a brand new bank and confirm it reaches the
+    // same state purely from the recorded events - our crash-recovery path.
+    let replayed = Bank::replay(bank.journal_bytes()).expect("journal should replay cleanly");
+    println!("\nReplayed accounts:");
+    for account in replayed.list_accounts() {
+        println!("{:?}", account);
+    }
+
+    // Import a few accounts from plain string records, e.g. rows read
+    // from a CSV file: id, name, balance (as dollars-and-cents text), authority.
+    let import_spec = [
+        Conversion::Integer,
+        Conversion::String,
+        Conversion::Currency,
+        Conversion::Integer,
+    ];
+    let rows = vec![
+        vec!["0".to_string(), "Frank".to_string(), "1234.50".to_string(), "1006".to_string()],
+        vec!["0".to_string(), "Grace".to_string(), "99.00".to_string(), "1007".to_string()],
+    ];
+    match bank.import_records(&rows, &import_spec) {
+        Ok(ids) => println!("\nImported accounts with IDs: {:?}", ids),
+        Err(e) => println!("\nImport failed: {}", e),
+    }
+
+    // A malformed record reports exactly which column failed
+    let bad_rows = vec![vec![
+        "0".to_string(),
+        "Heidi".to_string(),
+        "50.00".to_string(),
+        "not-a-number".to_string(),
+    ]];
+    if let Err(e) = bank.import_records(&bad_rows, &import_spec) {
+        println!("Import rejected: {}", e);
+    }
+
+    // Swap in the file-backed store: accounts written here are still on
+    // disk the next time a Bank is built from the same directory.
+    let mut file_bank = Bank::with_store(FileStore::new("./bank_data")).expect("failed to open file store");
+    file_bank.add_account(Account::new(0, "Eve".to_string(), 75_000, 1005)).ok();
+    println!("\nFile-backed accounts:");
+    for account in file_bank.list_accounts() {
+        println!("{:?}", account);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,143 @@
+// Storage backends for `Bank`, modeled on the ethstore `KeyDirectory`
+// pattern: the bank doesn't know or care whether accounts live in memory
+// or on disk, it just talks to whatever implements `AccountStore`.
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::RwLock;
+
+use crate::Account;
+
+// Every backend must be able to load the full account set, write a single
+// account back (insert or overwrite), and delete one by id.
+pub trait AccountStore {
+    fn load(&self) -> Result<Vec<Account>, String>;
+    fn update(&self, acc: Account) -> Result<Account, String>;
+    fn remove(&self, id: u32) -> Result<(), String>;
+}
+
+// In-memory backend. RwLock lets many readers (load) run concurrently and
+// still allows a writer (update/remove) to get exclusive access, which is
+// why `update`/`remove` only need `&self` rather than `&mut self`.
+pub struct MemoryStore {
+    data: RwLock<HashMap<u32, Account>>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        MemoryStore {
+            data: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+impl AccountStore for MemoryStore {
+    fn load(&self) -> Result<Vec<Account>, String> {
+        let data = self.data.read().map_err(|_| "account store lock poisoned".to_string())?;
+        Ok(data.values().cloned().collect())
+    }
+
+    fn update(&self, acc: Account) -> Result<Account, String> {
+        let mut data = self.data.write().map_err(|_| "account store lock poisoned".to_string())?;
+        data.insert(acc.id, acc.clone());
+        Ok(acc)
+    }
+
+    fn remove(&self, id: u32) -> Result<(), String> {
+        let mut data = self.data.write().map_err(|_| "account store lock poisoned".to_string())?;
+        data.remove(&id)
+            .map(|_| ())
+            .ok_or_else(|| format!("Account with ID {} not found", id))
+    }
+}
+
+// File-backed store. Each account is serialized to its own
+// `<dir>/<id>.account` file so the bank's state survives a process
+// restart - `Bank::with_store` just calls `load()` again on startup.
+pub struct FileStore {
+    dir: PathBuf,
+}
+
+impl FileStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        FileStore { dir: dir.into() }
+    }
+
+    fn path_for(&self, id: u32) -> PathBuf {
+        self.dir.join(format!("{}.account", id))
+    }
+}
+
+impl AccountStore for FileStore {
+    fn load(&self) -> Result<Vec<Account>, String> {
+        // An empty/missing directory just means no accounts yet.
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(ref e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.to_string()),
+        };
+
+        let mut accounts = Vec::new();
+        for entry in entries {
+            let entry = entry.map_err(|e| e.to_string())?;
+            let path = entry.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("account") {
+                continue;
+            }
+
+            let bytes = fs::read(&path).map_err(|e| e.to_string())?;
+            let mut pos = 0usize;
+            let id = read_line(&bytes, &mut pos)?
+                .parse::<u32>()
+                .map_err(|e| e.to_string())?;
+            // The name is length-prefixed rather than just its own line, so
+            // a name containing '\n' can't shift every field after it out
+            // of alignment.
+            let name_len = read_line(&bytes, &mut pos)?
+                .parse::<usize>()
+                .map_err(|e| e.to_string())?;
+            let name_bytes = bytes
+                .get(pos..pos + name_len)
+                .ok_or("account file truncated reading name")?;
+            let name = String::from_utf8(name_bytes.to_vec()).map_err(|e| e.to_string())?;
+            pos += name_len;
+            if bytes.get(pos) != Some(&b'\n') {
+                return Err("account file truncated after name".to_string());
+            }
+            pos += 1;
+            let balance = read_line(&bytes, &mut pos)?
+                .parse::<u64>()
+                .map_err(|e| e.to_string())?;
+            let authority = read_line(&bytes, &mut pos)?
+                .parse::<u64>()
+                .map_err(|e| e.to_string())?;
+            accounts.push(Account::new(id, name, balance, authority));
+        }
+        Ok(accounts)
+    }
+
+    fn update(&self, acc: Account) -> Result<Account, String> {
+        fs::create_dir_all(&self.dir).map_err(|e| e.to_string())?;
+        let mut contents = format!("{}\n{}\n", acc.id, acc.name.len()).into_bytes();
+        contents.extend_from_slice(acc.name.as_bytes());
+        contents.extend_from_slice(format!("\n{}\n{}\n", acc.balance, acc.authority).as_bytes());
+        fs::write(self.path_for(acc.id), contents).map_err(|e| e.to_string())?;
+        Ok(acc)
+    }
+
+    fn remove(&self, id: u32) -> Result<(), String> {
+        fs::remove_file(self.path_for(id))
+            .map_err(|_| format!("Account with ID {} not found", id))
+    }
+}
+
+// Read one '\n'-terminated line of text starting at `*pos`, advancing past
+// the newline. Used for the fixed fields around the length-prefixed name,
+// which can never themselves contain a newline.
+fn read_line(bytes: &[u8], pos: &mut usize) -> Result<String, String> {
+    let rest = &bytes[*pos..];
+    let nl = rest.iter().position(|&b| b == b'\n').ok_or("account file truncated")?;
+    let line = std::str::from_utf8(&rest[..nl]).map_err(|e| e.to_string())?.to_string();
+    *pos += nl + 1;
+    Ok(line)
+}